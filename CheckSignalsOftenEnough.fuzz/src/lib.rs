@@ -1,16 +1,20 @@
 //! This crate exists to test the equivalence of the two versions of
-//! `timespec_difference_at_least` from `CheckSignalsOftenEnough.c`.
+//! `timespec_difference_at_least` from `CheckSignalsOftenEnough.c`,
+//! plus a pure-Rust reference implementation that cannot share a bug
+//! with either of them.
 //!
 //! When used as a library crate, it provides safe wrappers for both
-//! versions of that function; however, its _purpose_ is to be a
-//! container for the unit test at the bottom of this file.
+//! C versions of that function, and the Rust reference version;
+//! however, its _purpose_ is to be a container for the unit test at
+//! the bottom of this file.
 //!
 //! Note that this crate _actually_ tests the code in `tdal.c`, not
 //! the code in `../../CheckSignalsOftenEnough.c`.  Presently the
 //! two copies of this code must be kept in sync manually.
 
 use core::ptr;
-use libc::{c_int, c_long, timespec};
+use core::time::Duration;
+use libc::{c_int, c_long, time_t, timespec};
 
 const ONE_S_IN_NS: u32 = 1_000_000_000;
 
@@ -68,6 +72,190 @@ pub fn timespec_difference_at_least_cases(
     rv != 0
 }
 
+/// Pure-Rust reference implementation of the same predicate, computed
+/// in widened `i128` arithmetic so that it cannot overflow for any
+/// in-range `timespec` and therefore serves as an independent source
+/// of truth rather than a copy of either C branch.
+///
+/// `min_ns` is restricted to sub-second values for parity with the C
+/// functions above; for a threshold of a second or more, use
+/// [`timespec_difference_at_least_duration`].
+pub fn timespec_difference_at_least_rust(
+    after: &timespec,
+    before: &timespec,
+    min_ns: u32,
+) -> bool {
+    assert!(min_ns < ONE_S_IN_NS, "min_ns must be less than 1 second");
+    timespec_difference_at_least_duration(after, before, Duration::from_nanos(min_ns as u64))
+}
+
+/// Like [`timespec_difference_at_least_rust`], but accepts a
+/// `threshold` of arbitrary magnitude instead of a sub-second `u32`
+/// count of nanoseconds, so that "has at least N seconds elapsed"
+/// checks don't have to be built out of a 1-second ceiling.
+pub fn timespec_difference_at_least_duration(
+    after: &timespec,
+    before: &timespec,
+    threshold: Duration,
+) -> bool {
+    let delta_ns = timespec_to_nanoseconds(after) - timespec_to_nanoseconds(before);
+    delta_ns >= duration_to_nanoseconds(threshold)
+}
+
+/// Widens a `Duration` to total nanoseconds in `i128`, matching the
+/// arithmetic `timespec_to_nanoseconds` uses for `timespec`.
+fn duration_to_nanoseconds(d: Duration) -> i128 {
+    d.as_secs() as i128 * 1_000_000_000 + d.subsec_nanos() as i128
+}
+
+/// Errors returned by the `checked_*` API when a `tv_nsec` field (or
+/// a `min_ns` argument standing in for one) is outside the valid
+/// range `0..ONE_S_IN_NS`, instead of the panics used elsewhere in
+/// this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimespecError {
+    /// The offending raw value, which may be negative or `>= 1e9`.
+    NanosecondsOutOfRange(c_long),
+}
+
+impl core::fmt::Display for TimespecError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TimespecError::NanosecondsOutOfRange(ns) => write!(
+                f,
+                "nanoseconds value {ns} is out of range (expected 0..{ONE_S_IN_NS})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TimespecError {}
+
+/// A validated count of nanoseconds within a single second, i.e. a
+/// value known to be in `0..ONE_S_IN_NS`. Mirrors the role of
+/// `std::time::Duration`'s subsec fields and rustix's own
+/// `Nsecs`/`Timespec` validation, but scoped to what this crate needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Nanoseconds(u32);
+
+impl Nanoseconds {
+    /// Validates `ns`, which is taken as a raw `c_long` because that
+    /// is the type of `timespec::tv_nsec` and it is not otherwise
+    /// guaranteed to be non-negative or in range.
+    pub fn new(ns: c_long) -> Result<Self, TimespecError> {
+        if (0..ONE_S_IN_NS as c_long).contains(&ns) {
+            Ok(Nanoseconds(ns as u32))
+        } else {
+            Err(TimespecError::NanosecondsOutOfRange(ns))
+        }
+    }
+
+    /// Returns the validated value as a plain `u32`.
+    pub fn get(self) -> u32 {
+        self.0
+    }
+}
+
+/// Non-panicking counterpart to [`timespec_difference_at_least_rust`]:
+/// instead of asserting that `min_ns` is in range (and taking both
+/// `tv_nsec` fields on trust), it validates all three and returns a
+/// [`TimespecError`] when one is not.
+pub fn checked_timespec_difference_at_least(
+    after: &timespec,
+    before: &timespec,
+    min_ns: u32,
+) -> Result<bool, TimespecError> {
+    Nanoseconds::new(after.tv_nsec)?;
+    Nanoseconds::new(before.tv_nsec)?;
+    Nanoseconds::new(min_ns as c_long)?;
+    // Go straight to the Duration-based implementation rather than
+    // through `timespec_difference_at_least_rust`, whose own
+    // `assert!` on `min_ns` would otherwise duplicate the check above.
+    Ok(timespec_difference_at_least_duration(
+        after,
+        before,
+        Duration::from_nanos(min_ns as u64),
+    ))
+}
+
+/// Carries an out-of-range (including negative) `tv_nsec` into
+/// `tv_sec`, so that afterward `tv_nsec` is in `0..ONE_S_IN_NS`.
+/// `timespec` values built by hand (as in the property tests below)
+/// are not guaranteed to start out normalized, and the behavior of
+/// the C code on denormalized input is otherwise unspecified.
+pub fn normalize(ts: &mut timespec) {
+    let carry = ts.tv_nsec.div_euclid(ONE_S_IN_NS as c_long);
+    let rem = ts.tv_nsec.rem_euclid(ONE_S_IN_NS as c_long);
+    ts.tv_sec = ts.tv_sec.saturating_add(carry as time_t);
+    ts.tv_nsec = rem;
+}
+
+/// The largest representable, normalized `timespec`: `tv_sec` at
+/// `time_t::MAX` and `tv_nsec` at its maximum valid value. Used as the
+/// saturation sentinel by [`timespec_add`]/[`timespec_subtract`]/
+/// [`timespec_from_nanoseconds`] when a result would otherwise
+/// overflow `time_t`.
+pub const TIMESPEC_MAX: timespec = timespec {
+    tv_sec: time_t::MAX,
+    tv_nsec: (ONE_S_IN_NS - 1) as c_long,
+};
+
+/// The smallest representable, normalized `timespec`: `tv_sec` at
+/// `time_t::MIN` and `tv_nsec` at zero. The saturation sentinel for
+/// results that would otherwise underflow `time_t`.
+pub const TIMESPEC_MIN: timespec = timespec {
+    tv_sec: time_t::MIN,
+    tv_nsec: 0,
+};
+
+/// Returns whether `ts.tv_nsec` is in the normalized range
+/// `0..ONE_S_IN_NS`. Unlike [`Nanoseconds::new`] this takes the whole
+/// `timespec` by reference, for symmetry with the other helpers in
+/// this module.
+pub fn timespec_is_valid(ts: &timespec) -> bool {
+    Nanoseconds::new(ts.tv_nsec).is_ok()
+}
+
+/// Converts a `timespec` to a total nanosecond count in widened
+/// `i128` arithmetic, which cannot overflow for any representable
+/// `time_t`. Does not require `ts` to be normalized.
+pub fn timespec_to_nanoseconds(ts: &timespec) -> i128 {
+    ts.tv_sec as i128 * 1_000_000_000 + ts.tv_nsec as i128
+}
+
+/// Converts a total nanosecond count back to a normalized `timespec`,
+/// saturating at [`TIMESPEC_MIN`]/[`TIMESPEC_MAX`] rather than
+/// wrapping if `ns` is out of `time_t`'s representable range.
+pub fn timespec_from_nanoseconds(ns: i128) -> timespec {
+    let min_ns = timespec_to_nanoseconds(&TIMESPEC_MIN);
+    let max_ns = timespec_to_nanoseconds(&TIMESPEC_MAX);
+    let ns = ns.clamp(min_ns, max_ns);
+    timespec {
+        tv_sec: ns.div_euclid(1_000_000_000) as time_t,
+        tv_nsec: ns.rem_euclid(1_000_000_000) as c_long,
+    }
+}
+
+/// Compares two `timespec`s by the instant they represent, not by
+/// lexicographic field order (though the two agree for normalized
+/// input; see the `cmp_matches_lexicographic_order_when_normalized`
+/// property test below).
+pub fn timespec_cmp(a: &timespec, b: &timespec) -> core::cmp::Ordering {
+    timespec_to_nanoseconds(a).cmp(&timespec_to_nanoseconds(b))
+}
+
+/// Adds two `timespec`s, normalizing and saturating the result at
+/// [`TIMESPEC_MIN`]/[`TIMESPEC_MAX`] rather than wrapping `time_t`.
+pub fn timespec_add(a: &timespec, b: &timespec) -> timespec {
+    timespec_from_nanoseconds(timespec_to_nanoseconds(a) + timespec_to_nanoseconds(b))
+}
+
+/// Subtracts `b` from `a`, normalizing and saturating the result at
+/// [`TIMESPEC_MIN`]/[`TIMESPEC_MAX`] rather than wrapping `time_t`.
+pub fn timespec_subtract(a: &timespec, b: &timespec) -> timespec {
+    timespec_from_nanoseconds(timespec_to_nanoseconds(a) - timespec_to_nanoseconds(b))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,33 +270,89 @@ mod tests {
         ///   after.tv_sec = before.tv_sec + 1
         ///   after.tv_sec > before.tv_sec + 1
         ///
-        /// The range of the generated tv_sec values are constrained
-        /// to ±2²⁴ to keep the search space reasonable; neither
-        /// version of the function has a strong dependence on the
-        /// absolute magnitude of either input's tv_sec field.
+        /// Two of the five equally weighted `sec_before` arms keep it
+        /// within ±2²⁴ of zero to keep the search space
+        /// reasonable, since neither version of the function has a
+        /// strong dependence on the absolute magnitude of either
+        /// input's `tv_sec` field. But `time_t` is 64 bits wide on
+        /// modern targets (some platforms widened it specifically to
+        /// survive the y2038 rollover at `i32::MAX` seconds), so we
+        /// also cluster `sec_before` around the known danger points:
+        /// zero, the y2038 boundary, and the extremes of `time_t`
+        /// itself.
+        ///
+        /// `sec_delta` (and therefore the seconds *difference*
+        /// actually fed to the C functions) stays capped at ±2²⁴
+        /// here regardless of `sec_before`, so on its own this strategy
+        /// only covers large absolute `tv_sec` magnitudes, not a huge
+        /// *difference*; see `arb_timespec_pair_extreme_difference`
+        /// below for that.
         ///
         /// We cannot return a pair of timespec structs because
         /// libc::timespec does not implement Debug, so instead
         /// we return a pair of pairs and convert to timespec
         /// structs in the actual test function.
-        fn arb_timespec_pair()(
-            sec_before in -16_777_216 ..= 16_777_216,
+        fn arb_timespec_pair_moderate_difference()(
+            sec_before in prop_oneof![
+                -16_777_216_i64 ..= 16_777_216_i64,
+                Just(0_i64),
+                (i32::MAX as i64 - 1_000) ..= (i32::MAX as i64 + 1_000),
+                time_t::MIN ..= (time_t::MIN + 1_000),
+                (time_t::MAX - 1_000_000_000) ..= time_t::MAX,
+            ],
             sec_delta in prop_oneof![
-                Just(0),
-                Just(1),
-                -16_777_216_i32 ..= -1,
-                2_i32 ..= 16_777_216,
+                Just(0_i64),
+                Just(1_i64),
+                -16_777_216_i64 ..= -1_i64,
+                2_i64 ..= 16_777_216_i64,
             ],
             ns_after in 0u32..ONE_S_IN_NS,
             ns_before in 0u32..ONE_S_IN_NS,
-        ) -> ((i32, u32), (i32, u32)) {
+        ) -> ((time_t, u32), (time_t, u32)) {
             (
-                (sec_before + sec_delta, ns_after),
+                (sec_before.saturating_add(sec_delta), ns_after),
                 (sec_before, ns_before)
             )
         }
     }
 
+    prop_compose! {
+        /// Draws `after`/`before` independently from opposite ends of
+        /// `time_t`, so the seconds *difference* fed to the C
+        /// functions can itself be huge: large enough that the "mul"
+        /// branch's `diff_sec * 1e9` -- and even the plain
+        /// `after.tv_sec - before.tv_sec` it starts from -- can
+        /// overflow `c_long`. That is deliberate: whether the C code
+        /// handles this gracefully or hits signed-overflow UB is
+        /// exactly what this generator is meant to surface, unlike
+        /// `arb_timespec_pair_moderate_difference` above.
+        fn arb_timespec_pair_extreme_difference()(
+            near_min in time_t::MIN ..= (time_t::MIN + 1_000_000),
+            near_max in (time_t::MAX - 1_000_000) ..= time_t::MAX,
+            min_is_after in any::<bool>(),
+            ns_after in 0u32..ONE_S_IN_NS,
+            ns_before in 0u32..ONE_S_IN_NS,
+        ) -> ((time_t, u32), (time_t, u32)) {
+            if min_is_after {
+                ((near_min, ns_after), (near_max, ns_before))
+            } else {
+                ((near_max, ns_after), (near_min, ns_before))
+            }
+        }
+    }
+
+    /// Combines the two strategies above with equal weight: half the
+    /// cases stay in the moderate-difference regime the "cases"
+    /// branch's four-way logic was designed around, and the other half
+    /// probe the huge-difference regime where the "mul" branch could
+    /// overflow `c_long`.
+    fn arb_timespec_pair() -> impl Strategy<Value = ((time_t, u32), (time_t, u32))> {
+        prop_oneof![
+            arb_timespec_pair_moderate_difference(),
+            arb_timespec_pair_extreme_difference(),
+        ]
+    }
+
     proptest! {
         #[test]
         fn timespec_differences_equivalent(
@@ -124,15 +368,277 @@ mod tests {
                 tv_sec: s_before.into(),
                 tv_nsec: ns_before.into(),
             };
+            let m = timespec_difference_at_least_mul(&after, &before, min_ns);
+            let c = timespec_difference_at_least_cases(&after, &before, min_ns);
+            let r = timespec_difference_at_least_rust(&after, &before, min_ns);
+            assert_eq!(
+                m, c,
+                "mismatch (mul vs cases): after={}.{:09} before={}.{:09} min_ns=0.{:09} mul={} cases={}",
+                after.tv_sec, after.tv_nsec,
+                before.tv_sec, before.tv_nsec,
+                min_ns, m, c
+            );
+            assert_eq!(
+                m, r,
+                "mismatch (mul vs rust): after={}.{:09} before={}.{:09} min_ns=0.{:09} mul={} rust={}",
+                after.tv_sec, after.tv_nsec,
+                before.tv_sec, before.tv_nsec,
+                min_ns, m, r
+            );
+        }
+    }
+
+    prop_compose! {
+        /// Generates deliberately denormalized timespecs, with
+        /// `tv_nsec` ranging from -1e9 up to 2e9, to be
+        /// fed through [`normalize`] before comparison. The C code's
+        /// behavior on denormalized input is otherwise unspecified,
+        /// so today this is untested.
+        fn arb_denormalized_timespec_pair()(
+            sec_before in -16_777_216_i64 ..= 16_777_216_i64,
+            sec_delta in prop_oneof![
+                Just(0_i64),
+                Just(1_i64),
+                -16_777_216_i64 ..= -1_i64,
+                2_i64 ..= 16_777_216_i64,
+            ],
+            ns_after in -1_000_000_000_i64 ..= 2_000_000_000_i64,
+            ns_before in -1_000_000_000_i64 ..= 2_000_000_000_i64,
+        ) -> ((time_t, c_long), (time_t, c_long)) {
+            (
+                (sec_before.saturating_add(sec_delta), ns_after as c_long),
+                (sec_before, ns_before as c_long)
+            )
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn denormalized_timespecs_equivalent_after_normalize(
+            ((s_after, ns_after),
+             (s_before, ns_before)) in arb_denormalized_timespec_pair(),
+            min_ns in 0..ONE_S_IN_NS
+        ) {
+            let mut after = timespec { tv_sec: s_after, tv_nsec: ns_after };
+            let mut before = timespec { tv_sec: s_before, tv_nsec: ns_before };
+            normalize(&mut after);
+            normalize(&mut before);
+
             let m = timespec_difference_at_least_mul(&after, &before, min_ns);
             let c = timespec_difference_at_least_cases(&after, &before, min_ns);
             assert_eq!(
                 m, c,
-                "mismatch: after={}.{:09} before={}.{:09} min_ns=0.{:09} mul={} cases={}",
+                "mismatch after normalize: after={}.{:09} before={}.{:09} min_ns=0.{:09} mul={} cases={}",
                 after.tv_sec, after.tv_nsec,
                 before.tv_sec, before.tv_nsec,
                 min_ns, m, c
             );
         }
     }
+
+    #[test]
+    fn nanoseconds_new_accepts_valid_range_and_rejects_out_of_range() {
+        assert_eq!(Nanoseconds::new(0).map(Nanoseconds::get), Ok(0));
+        assert_eq!(
+            Nanoseconds::new(ONE_S_IN_NS as c_long - 1).map(Nanoseconds::get),
+            Ok(ONE_S_IN_NS - 1)
+        );
+        assert_eq!(
+            Nanoseconds::new(-1),
+            Err(TimespecError::NanosecondsOutOfRange(-1))
+        );
+        assert_eq!(
+            Nanoseconds::new(ONE_S_IN_NS as c_long),
+            Err(TimespecError::NanosecondsOutOfRange(ONE_S_IN_NS as c_long))
+        );
+    }
+
+    #[test]
+    fn checked_difference_rejects_out_of_range_tv_nsec_and_min_ns() {
+        let valid = timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        let negative_nsec = timespec {
+            tv_sec: 0,
+            tv_nsec: -1,
+        };
+        let overflowing_nsec = timespec {
+            tv_sec: 0,
+            tv_nsec: ONE_S_IN_NS as c_long,
+        };
+        assert_eq!(
+            checked_timespec_difference_at_least(&negative_nsec, &valid, 0),
+            Err(TimespecError::NanosecondsOutOfRange(-1))
+        );
+        assert_eq!(
+            checked_timespec_difference_at_least(&valid, &overflowing_nsec, 0),
+            Err(TimespecError::NanosecondsOutOfRange(ONE_S_IN_NS as c_long))
+        );
+        assert_eq!(
+            checked_timespec_difference_at_least(&valid, &valid, ONE_S_IN_NS),
+            Err(TimespecError::NanosecondsOutOfRange(ONE_S_IN_NS as c_long))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn checked_difference_matches_rust_reference_for_valid_input(
+            ((s_after, ns_after),
+             (s_before, ns_before)) in arb_timespec_pair(),
+            min_ns in 0..ONE_S_IN_NS
+        ) {
+            let after = timespec { tv_sec: s_after, tv_nsec: ns_after as c_long };
+            let before = timespec { tv_sec: s_before, tv_nsec: ns_before as c_long };
+            let expected = timespec_difference_at_least_rust(&after, &before, min_ns);
+            assert_eq!(
+                checked_timespec_difference_at_least(&after, &before, min_ns),
+                Ok(expected)
+            );
+        }
+    }
+
+    prop_compose! {
+        /// Generates a normalized, moderate-magnitude `timespec`,
+        /// with `tv_sec` kept within ±10⁶, narrower than the ±2²⁴
+        /// range of `arb_timespec_pair` above, so that
+        /// `timespec_add`/`timespec_subtract` stay well
+        /// clear of `time_t` saturation in the algebraic-law tests.
+        fn arb_valid_timespec()(
+            tv_sec in -1_000_000_i64 ..= 1_000_000_i64,
+            tv_nsec in 0u32..ONE_S_IN_NS,
+        ) -> (time_t, c_long) {
+            (tv_sec, tv_nsec as c_long)
+        }
+    }
+
+    prop_compose! {
+        /// Like `arb_valid_timespec`, but non-negative, so that it
+        /// is unambiguously a "move away from zero" delta: adding it
+        /// to `TIMESPEC_MAX` (or subtracting it from `TIMESPEC_MIN`)
+        /// can only push further past the saturation boundary, never
+        /// pull back within range.
+        fn arb_nonneg_timespec()(
+            tv_sec in 0_i64 ..= 1_000_000_i64,
+            tv_nsec in 0u32..ONE_S_IN_NS,
+        ) -> (time_t, c_long) {
+            (tv_sec, tv_nsec as c_long)
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn cmp_matches_lexicographic_order_when_normalized(
+            (a_sec, a_nsec) in arb_valid_timespec(),
+            (b_sec, b_nsec) in arb_valid_timespec(),
+        ) {
+            let a = timespec { tv_sec: a_sec, tv_nsec: a_nsec };
+            let b = timespec { tv_sec: b_sec, tv_nsec: b_nsec };
+            let by_nanoseconds = timespec_cmp(&a, &b);
+            let by_fields = a_sec.cmp(&b_sec).then(a_nsec.cmp(&b_nsec));
+            assert_eq!(by_nanoseconds, by_fields);
+        }
+
+        #[test]
+        fn subtract_undoes_add_within_representable_range(
+            (a_sec, a_nsec) in arb_valid_timespec(),
+            (d_sec, d_nsec) in arb_valid_timespec(),
+        ) {
+            let a = timespec { tv_sec: a_sec, tv_nsec: a_nsec };
+            let d = timespec { tv_sec: d_sec, tv_nsec: d_nsec };
+            let result = timespec_subtract(&timespec_add(&a, &d), &d);
+            assert_eq!(
+                (result.tv_sec, result.tv_nsec), (a_sec, a_nsec),
+                "sub(add(a,d),d) != a for a={a_sec}.{a_nsec:09} d={d_sec}.{d_nsec:09}"
+            );
+        }
+
+        #[test]
+        fn add_and_subtract_saturate_instead_of_wrapping(
+            (a_sec, a_nsec) in arb_nonneg_timespec(),
+        ) {
+            let a = timespec { tv_sec: a_sec, tv_nsec: a_nsec };
+            let up = timespec_add(&TIMESPEC_MAX, &a);
+            assert_eq!((up.tv_sec, up.tv_nsec), (TIMESPEC_MAX.tv_sec, TIMESPEC_MAX.tv_nsec));
+            let down = timespec_subtract(&TIMESPEC_MIN, &a);
+            assert_eq!((down.tv_sec, down.tv_nsec), (TIMESPEC_MIN.tv_sec, TIMESPEC_MIN.tv_nsec));
+        }
+
+        #[test]
+        fn to_from_nanoseconds_roundtrips(
+            (tv_sec, tv_nsec) in arb_valid_timespec(),
+        ) {
+            let ts = timespec { tv_sec, tv_nsec };
+            let roundtripped = timespec_from_nanoseconds(timespec_to_nanoseconds(&ts));
+            assert_eq!((roundtripped.tv_sec, roundtripped.tv_nsec), (tv_sec, tv_nsec));
+        }
+
+        #[test]
+        fn is_valid_accepts_normalized_input(
+            (tv_sec, tv_nsec) in arb_valid_timespec(),
+        ) {
+            assert!(timespec_is_valid(&timespec { tv_sec, tv_nsec }));
+        }
+    }
+
+    #[test]
+    fn is_valid_rejects_out_of_range_nsec() {
+        assert!(!timespec_is_valid(&timespec {
+            tv_sec: 0,
+            tv_nsec: ONE_S_IN_NS as c_long,
+        }));
+        assert!(!timespec_is_valid(&timespec {
+            tv_sec: 0,
+            tv_nsec: -1,
+        }));
+    }
+
+    prop_compose! {
+        /// Generates thresholds spanning multiple seconds, unlike
+        /// `min_ns in 0..ONE_S_IN_NS` above, to exercise
+        /// [`timespec_difference_at_least_duration`]'s removal of the
+        /// sub-second ceiling.
+        fn arb_multi_second_threshold()(
+            secs in 0u64..=1_000_000,
+            nanos in 0u32..ONE_S_IN_NS,
+        ) -> Duration {
+            Duration::new(secs, nanos)
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn duration_threshold_matches_field_wise_reference(
+            (a_sec, a_nsec) in arb_valid_timespec(),
+            (b_sec, b_nsec) in arb_valid_timespec(),
+            threshold in arb_multi_second_threshold(),
+        ) {
+            let after = timespec { tv_sec: a_sec, tv_nsec: a_nsec };
+            let before = timespec { tv_sec: b_sec, tv_nsec: b_nsec };
+            // Independent of `timespec_to_nanoseconds`/
+            // `duration_to_nanoseconds`: decomposes the threshold
+            // into whole seconds plus sub-second nanoseconds and
+            // compares directly against the seconds/nanoseconds
+            // difference, so a bug in either of those helpers
+            // wouldn't cancel out between "expected" and "actual".
+            let sec_diff = a_sec as i128 - b_sec as i128;
+            let nsec_diff = a_nsec as i128 - b_nsec as i128;
+            let expected = (sec_diff - threshold.as_secs() as i128) * 1_000_000_000
+                + (nsec_diff - threshold.subsec_nanos() as i128)
+                >= 0;
+            let actual = timespec_difference_at_least_duration(&after, &before, threshold);
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn duration_threshold_exact_equality_boundary(
+            (b_sec, b_nsec) in arb_valid_timespec(),
+            threshold in arb_multi_second_threshold(),
+        ) {
+            let before = timespec { tv_sec: b_sec, tv_nsec: b_nsec };
+            let threshold_ns = duration_to_nanoseconds(threshold);
+            let after = timespec_from_nanoseconds(timespec_to_nanoseconds(&before) + threshold_ns);
+            assert!(timespec_difference_at_least_duration(&after, &before, threshold));
+        }
+    }
 }